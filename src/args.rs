@@ -0,0 +1,31 @@
+use crate::serialize::{CairoVersion, OutputFormat};
+use clap::Args;
+use std::path::PathBuf;
+
+#[derive(Debug, Args)]
+pub struct SerializeArgs {
+    /// Path to the proof file to serialize into calldata.
+    #[clap(long)]
+    pub proof: PathBuf,
+
+    /// Path to write the serialized calldata to.
+    #[clap(long)]
+    pub output: PathBuf,
+
+    /// Cairo version the proof was generated for; selects the version felt
+    /// appended to the calldata (or placed in each chunk's header when
+    /// `--max-felts-per-chunk` is set).
+    #[clap(long, value_enum, default_value = "cairo1")]
+    pub cairo_version: CairoVersion,
+
+    /// Representation used for the serialized calldata.
+    #[clap(long, value_enum, default_value = "decimal")]
+    pub output_format: OutputFormat,
+
+    /// Split the calldata into multiple files of at most this many felts each,
+    /// so it fits within a Starknet `invoke` transaction's calldata limit.
+    /// Writes `<output>.chunk0`, `<output>.chunk1`, ... plus an
+    /// `<output>.manifest.json` listing them, instead of a single `--output` file.
+    #[clap(long)]
+    pub max_felts_per_chunk: Option<usize>,
+}
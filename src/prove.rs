@@ -0,0 +1,40 @@
+use crate::sandbox::{run_confined, SandboxArgs};
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+#[derive(Debug, Args)]
+pub struct ProveArgs {
+    /// Directory containing the compiled program and its inputs, bind-mounted
+    /// read-write into the sandbox when `--sandbox` is set.
+    #[clap(long)]
+    pub input_dir: PathBuf,
+
+    /// Directory the prover writes its proof and trace files to, bind-mounted
+    /// read-write into the sandbox when `--sandbox` is set.
+    #[clap(long)]
+    pub output_dir: PathBuf,
+
+    #[clap(flatten)]
+    pub sandbox: SandboxArgs,
+}
+
+/// Runs the downloaded `cpu_air_prover` binary against `args.input_dir`,
+/// writing its proof to `args.output_dir`, confined to both by `args.sandbox`
+/// when requested.
+pub fn run_prover(args: &ProveArgs, stone_cli_dir: &std::path::Path) -> Result<()> {
+    let binary = stone_cli_dir.join("cpu_air_prover");
+    let binary_args = vec![
+        "--in".to_string(),
+        args.input_dir.display().to_string(),
+        "--out".to_string(),
+        args.output_dir.display().to_string(),
+    ];
+    run_confined(
+        &binary,
+        &binary_args,
+        &args.input_dir,
+        &args.output_dir,
+        &args.sandbox,
+    )
+}
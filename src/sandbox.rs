@@ -0,0 +1,139 @@
+use anyhow::{bail, Context, Result};
+use clap::Args;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// CLI flags controlling sandboxed execution of downloaded prover/corelib
+/// binaries. Flatten this into any command that invokes one, e.g.
+/// `#[clap(flatten)] pub sandbox: SandboxArgs`.
+#[derive(Debug, Clone, Args)]
+pub struct SandboxArgs {
+    /// Run prover/corelib binaries inside a bubblewrap sandbox confined to the
+    /// proof input/output directories, with network access dropped by default.
+    #[clap(long)]
+    pub sandbox: bool,
+
+    /// Fail instead of degrading to an unconfined run (with a warning) when `--sandbox`
+    /// is set but bubblewrap isn't available. Use this in CI.
+    #[clap(long, requires = "sandbox")]
+    pub require_sandbox: bool,
+}
+
+/// Confines a downloaded prover/corelib binary to its proof input and output
+/// directories before it runs, using bubblewrap (`bwrap`) on Linux: everything
+/// else on the filesystem is bind-mounted read-only, network access is dropped
+/// unless `allow_network` is set, and the child is killed if this process dies.
+///
+/// Degrades gracefully when `bwrap` isn't installed or the host isn't Linux: the
+/// command runs unconfined with a warning, unless `require_sandbox` is set, in
+/// which case that's a hard error.
+pub struct Sandbox {
+    pub input_dir: PathBuf,
+    pub output_dir: PathBuf,
+    pub allow_network: bool,
+    pub require_sandbox: bool,
+}
+
+impl Sandbox {
+    pub fn new(input_dir: impl Into<PathBuf>, output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            input_dir: input_dir.into(),
+            output_dir: output_dir.into(),
+            allow_network: false,
+            require_sandbox: false,
+        }
+    }
+
+    /// Wraps `command` in a `bwrap` jail when possible, or returns it unchanged.
+    pub fn wrap(&self, command: Command) -> Result<Command> {
+        if !cfg!(target_os = "linux") {
+            return self.unconfined("sandboxing is only supported on Linux", command);
+        }
+        let Some(bwrap) = find_on_path("bwrap") else {
+            return self.unconfined("bubblewrap (bwrap) not found on PATH", command);
+        };
+
+        let mut wrapped = Command::new(bwrap);
+        wrapped
+            .arg("--ro-bind")
+            .arg("/")
+            .arg("/")
+            .arg("--dev")
+            .arg("/dev")
+            .arg("--proc")
+            .arg("/proc")
+            .arg("--bind")
+            .arg(&self.input_dir)
+            .arg(&self.input_dir)
+            .arg("--bind")
+            .arg(&self.output_dir)
+            .arg(&self.output_dir)
+            .arg("--die-with-parent");
+
+        if !self.allow_network {
+            wrapped.arg("--unshare-net");
+        }
+
+        wrapped.arg("--").arg(command.get_program());
+        wrapped.args(command.get_args());
+        for (key, value) in command.get_envs() {
+            if let Some(value) = value {
+                wrapped.env(key, value);
+            }
+        }
+        if let Some(dir) = command.get_current_dir() {
+            wrapped.current_dir(dir);
+        }
+
+        Ok(wrapped)
+    }
+
+    fn unconfined(&self, reason: &str, command: Command) -> Result<Command> {
+        if self.require_sandbox {
+            bail!("Sandboxed execution was required but unavailable: {reason}");
+        }
+        eprintln!("warning: running prover unconfined ({reason})");
+        Ok(command)
+    }
+}
+
+fn find_on_path(program: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(program))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Runs `binary` with `binary_args`, confined by `sandbox_args` to `input_dir`
+/// and `output_dir` (both read-write) when `--sandbox` is set. This is
+/// the integration point every prover/corelib invocation should go through.
+pub fn run_confined(
+    binary: &Path,
+    binary_args: &[String],
+    input_dir: &Path,
+    output_dir: &Path,
+    sandbox_args: &SandboxArgs,
+) -> Result<()> {
+    let mut command = Command::new(binary);
+    command.args(binary_args);
+
+    let mut command = if sandbox_args.sandbox {
+        let jail = Sandbox {
+            input_dir: input_dir.to_path_buf(),
+            output_dir: output_dir.to_path_buf(),
+            allow_network: false,
+            require_sandbox: sandbox_args.require_sandbox,
+        };
+        jail.wrap(command)?
+    } else {
+        command
+    };
+
+    let status = command
+        .status()
+        .with_context(|| format!("Failed to execute {}", binary.display()))?;
+    if !status.success() {
+        bail!("{} exited with {}", binary.display(), status);
+    }
+    Ok(())
+}
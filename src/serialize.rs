@@ -6,11 +6,12 @@ use cairo_felt::Felt252;
 use cairo_proof_parser::parse;
 use clap::ValueEnum;
 use itertools::chain;
+use serde::Serialize;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use vec252::VecFelt252;
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 pub enum CairoVersion {
     Cairo0 = 0,
     Cairo1 = 1,
@@ -25,28 +26,143 @@ impl From<CairoVersion> for Felt252 {
     }
 }
 
+/// Representation used when writing the serialized calldata felts to disk.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// The original space-separated decimal string.
+    Decimal,
+    /// A JSON array of decimal-string felts.
+    Json,
+    /// A space-separated list of `0x`-prefixed hex felts.
+    Hex,
+}
+
 pub fn serialize_proof(args: &SerializeArgs) -> Result<()> {
     let proof_file = args.proof.clone();
     let (config, public_input, unsent_commitment, witness) = parse_proof_file(&proof_file)?;
 
-    let proof = chain!(
+    let proof: Vec<Felt252> = chain!(
         config.into_iter(),
         public_input.into_iter(),
         unsent_commitment.into_iter(),
         witness.into_iter()
-    );
+    )
+    .collect();
+
+    match args.max_felts_per_chunk {
+        Some(max_felts_per_chunk) => write_chunked_calldata(&proof, args, max_felts_per_chunk),
+        None => {
+            let calldata = chain!(proof.into_iter(), std::iter::once(args.cairo_version.into()));
+            let calldata_string = format_calldata(calldata, args.output_format);
+            fs::write(args.output.clone(), calldata_string)?;
+            Ok(())
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ChunkManifestEntry {
+    file: String,
+    felt_count: usize,
+}
 
-    let calldata = chain!(proof, std::iter::once(CairoVersion::Cairo1.into()));
+#[derive(Serialize)]
+struct ChunkManifest {
+    total_chunks: usize,
+    chunks: Vec<ChunkManifestEntry>,
+}
+
+/// Splits `proof` into `max_felts_per_chunk`-sized chunks, each written to its own
+/// file and prefixed with a `(chunk index, total chunks, cairo version felt)`
+/// header so an on-chain accumulator can reassemble them in order across
+/// multiple transactions. A manifest alongside the chunks lists their file names
+/// and felt counts so the caller can drive the multi-tx submission.
+fn write_chunked_calldata(
+    proof: &[Felt252],
+    args: &SerializeArgs,
+    max_felts_per_chunk: usize,
+) -> Result<()> {
+    // Each chunk is prefixed with a 3-felt header (index, total chunks, cairo
+    // version), so the data slice itself must leave room for it, or the emitted
+    // chunk would exceed the caller's requested felt cap.
+    const HEADER_LEN: usize = 3;
+    let data_felts_per_chunk = data_felts_per_chunk(max_felts_per_chunk, HEADER_LEN)?;
+
+    let cairo_version_felt: Felt252 = args.cairo_version.into();
+    let data_chunks: Vec<&[Felt252]> = proof.chunks(data_felts_per_chunk).collect();
+    let total_chunks = data_chunks.len();
+
+    let mut manifest = ChunkManifest {
+        total_chunks,
+        chunks: Vec::with_capacity(total_chunks),
+    };
 
-    let calldata_string = calldata
-        .map(|f| f.to_string())
-        .collect::<Vec<_>>()
-        .join(" ");
+    for (index, data_chunk) in data_chunks.into_iter().enumerate() {
+        let header = [
+            Felt252::from(index as u64),
+            Felt252::from(total_chunks as u64),
+            cairo_version_felt.clone(),
+        ];
+        let felt_count = header.len() + data_chunk.len();
+        let chunk_felts = chain!(header, data_chunk.iter().cloned());
+        let chunk_file_path = chunk_file_path(&args.output, index);
+        fs::write(&chunk_file_path, format_calldata(chunk_felts, args.output_format))?;
 
-    fs::write(args.output.clone(), calldata_string)?;
+        manifest.chunks.push(ChunkManifestEntry {
+            file: chunk_file_path
+                .file_name()
+                .expect("chunk file path has no file name")
+                .to_string_lossy()
+                .into_owned(),
+            felt_count,
+        });
+    }
+
+    fs::write(
+        manifest_file_path(&args.output),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
     Ok(())
 }
 
+/// Number of data felts a chunk may hold once `header_len` felts are reserved
+/// for its header, so `header_len + data_felts_per_chunk(...) <= max_felts_per_chunk`.
+/// Errors instead of silently clamping when there's no room left for any data.
+fn data_felts_per_chunk(max_felts_per_chunk: usize, header_len: usize) -> Result<usize> {
+    if max_felts_per_chunk <= header_len {
+        anyhow::bail!(
+            "--max-felts-per-chunk must be greater than {header_len} to leave room for the chunk header"
+        );
+    }
+    Ok(max_felts_per_chunk - header_len)
+}
+
+fn chunk_file_path(output: &Path, index: usize) -> PathBuf {
+    let mut file_name = output.as_os_str().to_os_string();
+    file_name.push(format!(".chunk{index}"));
+    PathBuf::from(file_name)
+}
+
+fn manifest_file_path(output: &Path) -> PathBuf {
+    let mut file_name = output.as_os_str().to_os_string();
+    file_name.push(".manifest.json");
+    PathBuf::from(file_name)
+}
+
+fn format_calldata(calldata: impl Iterator<Item = Felt252>, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Decimal => calldata.map(|f| f.to_string()).collect::<Vec<_>>().join(" "),
+        OutputFormat::Json => {
+            let felts = calldata.map(|f| f.to_string()).collect::<Vec<_>>();
+            serde_json::to_string(&felts).expect("Failed to serialize felts to JSON")
+        }
+        OutputFormat::Hex => calldata
+            .map(|f| format!("0x{}", f.to_bigint().to_str_radix(16)))
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
 fn parse_proof_file(proof_file: &Path) -> Result<(VecFelt252, VecFelt252, VecFelt252, VecFelt252)> {
     let proof_file_content = std::fs::read_to_string(proof_file)?;
     let parsed = parse(proof_file_content)?;
@@ -57,3 +173,22 @@ fn parse_proof_file(proof_file: &Path) -> Result<(VecFelt252, VecFelt252, VecFel
         serde_json::from_str(&parsed.witness.to_string())?,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_max_felts_per_chunk_too_small_for_the_header() {
+        assert!(data_felts_per_chunk(0, 3).is_err());
+        assert!(data_felts_per_chunk(3, 3).is_err());
+    }
+
+    #[test]
+    fn reserves_header_budget_within_the_requested_cap() {
+        let header_len = 3;
+        let data_felts = data_felts_per_chunk(10, header_len).unwrap();
+        assert_eq!(data_felts, 7);
+        assert!(data_felts + header_len <= 10);
+    }
+}